@@ -1,5 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Re-exported so the expansion of `packed_bits!` can paste identifiers
+// (e.g. `day` -> `set_day`) without requiring downstream crates to depend
+// on `paste` themselves.
+#[doc(hidden)]
+pub use paste::paste as __paste;
+
 /// Memory-efficient bit packing library.
 /// Define a packed_bits struct that stores multiple fields in a single integer.
 ///
@@ -46,8 +52,62 @@
 /// - u16 can hold 16 bits total, u32 can hold 32 bits, etc.
 /// - Each field gets a method with the same name to read its value
 /// - Values are stored from lowest bits to highest bits in declaration order
+/// - A field may instead be declared as `field: Type @ bits` to store it as
+///   `bool` (via `!= 0`) or any named type `T` with `From<$storage> for T` and
+///   `From<T> for $storage` impls, instead of the raw storage integer; if one
+///   field uses this form, every field of the struct must (a plain field can
+///   be written as e.g. `field: u8 @ bits` for `u8` storage)
+/// - Each field also gets `FIELD_BITS`, `FIELD_OFFSET`, and `FIELD_MASK`
+///   associated constants describing its position in the storage integer
 #[macro_export]
 macro_rules! packed_bits {
+    (
+       struct $name:ident($storage:ty) {
+            $(
+                $field:ident: $ty:ident @ $bits:expr,
+            )*
+        }
+    ) => {
+        #[derive(Copy, Clone)]
+        struct $name($storage);
+
+        impl $name {
+            const PACKED_BITS_FIT: () = assert!(
+                0 $(+ $bits)* <= ::core::mem::size_of::<$storage>() * 8,
+                "packed_bits!: sum of field bit widths exceeds the storage type's width"
+            );
+
+            fn new($($field: $ty),*) -> Self {
+                let () = Self::PACKED_BITS_FIT;
+
+                // `@to_raw_typed` shadows each `$field` with its converted
+                // `$storage` value, so packing below reuses the untyped
+                // `@pack` instead of a typed copy of the same arithmetic.
+                packed_bits!(@to_raw_typed $storage, [$($field: $ty @ $bits),*]);
+
+                Self(packed_bits!(@pack $storage, [$($field: $bits),*], 0))
+            }
+
+            /// Like [`Self::new`], but rejects field values that don't fit in
+            /// their allotted bits instead of silently truncating them.
+            fn try_new($($field: $ty),*) -> Result<Self, &'static str> {
+                let () = Self::PACKED_BITS_FIT;
+
+                // `@check_typed` shadows each `$field` with its already-converted
+                // `$storage` value, so packing below reuses that raw value
+                // instead of converting (and moving) the original argument again.
+                packed_bits!(@check_typed $storage, [$($field: $ty @ $bits),*]);
+
+                Ok(Self(packed_bits!(@pack $storage, [$($field: $bits),*], 0)))
+            }
+
+            packed_bits!(@impl_getters_typed $storage, [$($field: $ty @ $bits),*]);
+            packed_bits!(@impl_setters_typed $storage, [$($field: $ty @ $bits),*]);
+            packed_bits!(@impl_bytes $storage);
+            packed_bits!(@impl_consts_typed $storage, [$($field: $ty @ $bits),*]);
+        }
+    };
+
     (
        struct $name:ident($storage:ty) {
             $(
@@ -59,37 +119,46 @@ macro_rules! packed_bits {
         struct $name($storage);
 
         impl $name {
-            fn new($($field: $storage),*) -> Self {
-                let fields = [$($field),*];
-                let bit_sizes = [$($bits),*];
+            const PACKED_BITS_FIT: () = assert!(
+                0 $(+ $bits)* <= ::core::mem::size_of::<$storage>() * 8,
+                "packed_bits!: sum of field bit widths exceeds the storage type's width"
+            );
 
-                let mut packed = 0;
-                let mut offset = 0;
+            const fn new($($field: $storage),*) -> Self {
+                let () = Self::PACKED_BITS_FIT;
 
-                for i in 0..fields.len() {
-                    packed |= (fields[i] & ((1 << bit_sizes[i]) - 1)) << offset;
-                    offset += bit_sizes[i];
-                }
+                Self(packed_bits!(@pack $storage, [$($field: $bits),*], 0))
+            }
+
+            /// Like [`Self::new`], but rejects field values that don't fit in
+            /// their allotted bits instead of silently truncating them.
+            const fn try_new($($field: $storage),*) -> Result<Self, &'static str> {
+                let () = Self::PACKED_BITS_FIT;
 
-                Self(packed)
+                packed_bits!(@check $storage, [$($field: $bits),*]);
+
+                Ok(Self(packed_bits!(@pack $storage, [$($field: $bits),*], 0)))
             }
 
             packed_bits!(@impl_getters $storage, [$($field: $bits),*]);
+            packed_bits!(@impl_setters $storage, [$($field: $bits),*]);
+            packed_bits!(@impl_bytes $storage);
+            packed_bits!(@impl_consts $storage, [$($field: $bits),*]);
         }
 
     };
 
      (@impl_getters $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*]) => {
-        fn $first(&self) -> $storage {
-            self.0 & ((1 << $first_bits) - 1)
+        const fn $first(&self) -> $storage {
+            self.0 & packed_bits!(@mask $storage, $first_bits)
         }
 
         packed_bits!(@impl_getters $storage, [$($field: $bits),*], $first_bits);
     };
 
     (@impl_getters $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*], $offset:expr) => {
-        fn $first(&self) -> $storage {
-            (self.0 >> $offset) & ((1 << $first_bits) - 1)
+        const fn $first(&self) -> $storage {
+            (self.0 >> $offset) & packed_bits!(@mask $storage, $first_bits)
         }
 
         packed_bits!(@impl_getters $storage, [$($field: $bits),*], $offset + $first_bits);
@@ -97,6 +166,243 @@ macro_rules! packed_bits {
 
     (@impl_getters $storage:ty, [], $offset:expr) => {};
     (@impl_getters $storage:ty, []) => {};
+
+    (@pack $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*], $offset:expr) => {
+        (($first & packed_bits!(@mask $storage, $first_bits)) << $offset)
+            | packed_bits!(@pack $storage, [$($field: $bits),*], $offset + $first_bits)
+    };
+
+    (@pack $storage:ty, [], $offset:expr) => { 0 };
+
+    // A field's bitmask, computed without shifting by the storage type's full
+    // width (which would overflow when a field spans every bit, e.g. a single
+    // 8-bit field in a `u8`-backed struct).
+    (@mask $storage:ty, $bits:expr) => {
+        (!(0 as $storage)) >> ((::core::mem::size_of::<$storage>() as u32) * 8 - $bits)
+    };
+
+    (@check $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*]) => {
+        if $first > packed_bits!(@mask $storage, $first_bits) {
+            return Err(concat!(
+                "packed_bits!: field `",
+                stringify!($first),
+                "` does not fit in its allotted bits"
+            ));
+        }
+        packed_bits!(@check $storage, [$($field: $bits),*]);
+    };
+
+    (@check $storage:ty, []) => {};
+
+    (@impl_setters $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*]) => {
+        packed_bits!(@impl_setter $storage, $first, $first_bits, 0);
+        packed_bits!(@impl_setters $storage, [$($field: $bits),*], $first_bits);
+    };
+
+    (@impl_setters $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*], $offset:expr) => {
+        packed_bits!(@impl_setter $storage, $first, $first_bits, $offset);
+        packed_bits!(@impl_setters $storage, [$($field: $bits),*], $offset + $first_bits);
+    };
+
+    (@impl_setters $storage:ty, [], $offset:expr) => {};
+    (@impl_setters $storage:ty, []) => {};
+
+    (@impl_setter $storage:ty, $field:ident, $bits:expr, $offset:expr) => {
+        $crate::__paste! {
+            #[allow(non_snake_case)]
+            const fn [<set_ $field>](&mut self, value: $storage) {
+                let mask: $storage = packed_bits!(@mask $storage, $bits);
+                self.0 &= !(mask << $offset);
+                self.0 |= (value & mask) << $offset;
+            }
+
+            #[allow(non_snake_case)]
+            const fn [<with_ $field>](mut self, value: $storage) -> Self {
+                self.[<set_ $field>](value);
+                self
+            }
+        }
+    };
+
+    // Converts a typed field value into the raw storage representation.
+    // `bool` is converted with an `as` cast (same result as `core`'s
+    // `From<bool>` impls for the integer types).
+    (@to_storage bool, $storage:ty, $val:expr) => {
+        ($val as $storage)
+    };
+    (@to_storage $ty:ty, $storage:ty, $val:expr) => {
+        <$storage as From<$ty>>::from($val)
+    };
+
+    // Converts a raw storage value back into a typed field value.
+    // `bool` is special-cased since `core` has no `From<u8/u16/...> for bool`.
+    (@from_storage bool, $storage:ty, $val:expr) => {
+        ($val != 0)
+    };
+    (@from_storage $ty:ty, $storage:ty, $val:expr) => {
+        <$ty as From<$storage>>::from($val)
+    };
+
+    (@impl_getters_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*]) => {
+        fn $first(&self) -> $first_ty {
+            packed_bits!(@from_storage $first_ty, $storage, (self.0 & packed_bits!(@mask $storage, $first_bits)))
+        }
+
+        packed_bits!(@impl_getters_typed $storage, [$($field: $ty @ $bits),*], $first_bits);
+    };
+
+    (@impl_getters_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*], $offset:expr) => {
+        fn $first(&self) -> $first_ty {
+            packed_bits!(@from_storage $first_ty, $storage, ((self.0 >> $offset) & packed_bits!(@mask $storage, $first_bits)))
+        }
+
+        packed_bits!(@impl_getters_typed $storage, [$($field: $ty @ $bits),*], $offset + $first_bits);
+    };
+
+    (@impl_getters_typed $storage:ty, [], $offset:expr) => {};
+    (@impl_getters_typed $storage:ty, []) => {};
+
+    // Shadows each typed `$field` with its converted, `Copy` `$storage` value
+    // so callers (namely `new`) can pack it with the untyped `@pack` instead
+    // of keeping a second, divergent copy of the packing arithmetic.
+    (@to_raw_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*]) => {
+        let $first: $storage = packed_bits!(@to_storage $first_ty, $storage, $first);
+        packed_bits!(@to_raw_typed $storage, [$($field: $ty @ $bits),*]);
+    };
+
+    (@to_raw_typed $storage:ty, []) => {};
+
+    (@check_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*]) => {
+        // Shadow `$first` with its raw `$storage` value (an integer, so
+        // `Copy`) so later uses don't need to convert the original,
+        // possibly non-`Copy`, argument a second time.
+        let $first: $storage = packed_bits!(@to_storage $first_ty, $storage, $first);
+        if $first > packed_bits!(@mask $storage, $first_bits) {
+            return Err(concat!(
+                "packed_bits!: field `",
+                stringify!($first),
+                "` does not fit in its allotted bits"
+            ));
+        }
+        packed_bits!(@check_typed $storage, [$($field: $ty @ $bits),*]);
+    };
+
+    (@check_typed $storage:ty, []) => {};
+
+    (@impl_setters_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*]) => {
+        packed_bits!(@impl_setter_typed $storage, $first, $first_ty, $first_bits, 0);
+        packed_bits!(@impl_setters_typed $storage, [$($field: $ty @ $bits),*], $first_bits);
+    };
+
+    (@impl_setters_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*], $offset:expr) => {
+        packed_bits!(@impl_setter_typed $storage, $first, $first_ty, $first_bits, $offset);
+        packed_bits!(@impl_setters_typed $storage, [$($field: $ty @ $bits),*], $offset + $first_bits);
+    };
+
+    (@impl_setters_typed $storage:ty, [], $offset:expr) => {};
+    (@impl_setters_typed $storage:ty, []) => {};
+
+    (@impl_setter_typed $storage:ty, $field:ident, $ty:ty, $bits:expr, $offset:expr) => {
+        $crate::__paste! {
+            #[allow(non_snake_case)]
+            fn [<set_ $field>](&mut self, value: $ty) {
+                let raw: $storage = packed_bits!(@to_storage $ty, $storage, value);
+                let mask: $storage = packed_bits!(@mask $storage, $bits);
+                self.0 &= !(mask << $offset);
+                self.0 |= (raw & mask) << $offset;
+            }
+
+            #[allow(non_snake_case)]
+            fn [<with_ $field>](mut self, value: $ty) -> Self {
+                self.[<set_ $field>](value);
+                self
+            }
+        }
+    };
+
+    (@impl_bytes $storage:ty) => {
+        /// Returns the raw packed value, unpacking nothing.
+        const fn into_raw(self) -> $storage {
+            self.0
+        }
+
+        /// Wraps an already-packed raw value without validating its fields.
+        const fn from_raw(raw: $storage) -> Self {
+            Self(raw)
+        }
+
+        /// Serializes to big-endian bytes, as used by most wire packet formats.
+        fn to_be_bytes(self) -> [u8; ::core::mem::size_of::<$storage>()] {
+            self.0.to_be_bytes()
+        }
+
+        /// Serializes to little-endian bytes.
+        fn to_le_bytes(self) -> [u8; ::core::mem::size_of::<$storage>()] {
+            self.0.to_le_bytes()
+        }
+
+        /// Deserializes from big-endian bytes.
+        fn from_be_bytes(bytes: [u8; ::core::mem::size_of::<$storage>()]) -> Self {
+            Self(<$storage>::from_be_bytes(bytes))
+        }
+
+        /// Deserializes from little-endian bytes.
+        fn from_le_bytes(bytes: [u8; ::core::mem::size_of::<$storage>()]) -> Self {
+            Self(<$storage>::from_le_bytes(bytes))
+        }
+
+        /// Deserializes from a big-endian byte slice.
+        ///
+        /// # Panics
+        /// Panics if `bytes.len() != core::mem::size_of::<$storage>()`.
+        fn from_bytes(bytes: &[u8]) -> Self {
+            Self::try_from_bytes(bytes).expect("packed_bits!: slice length does not match storage size")
+        }
+
+        /// Like [`Self::from_bytes`], but returns `None` instead of panicking
+        /// when `bytes` isn't exactly `core::mem::size_of::<$storage>()` long.
+        fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+            let array = bytes.try_into().ok()?;
+            Some(Self::from_be_bytes(array))
+        }
+    };
+
+    (@impl_consts $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*]) => {
+        packed_bits!(@impl_const $storage, $first, $first_bits, 0);
+        packed_bits!(@impl_consts $storage, [$($field: $bits),*], $first_bits);
+    };
+
+    (@impl_consts $storage:ty, [$first:ident: $first_bits:expr $(, $field:ident: $bits:expr)*], $offset:expr) => {
+        packed_bits!(@impl_const $storage, $first, $first_bits, $offset);
+        packed_bits!(@impl_consts $storage, [$($field: $bits),*], $offset + $first_bits);
+    };
+
+    (@impl_consts $storage:ty, [], $offset:expr) => {};
+    (@impl_consts $storage:ty, []) => {};
+
+    (@impl_consts_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*]) => {
+        packed_bits!(@impl_const $storage, $first, $first_bits, 0);
+        packed_bits!(@impl_consts_typed $storage, [$($field: $ty @ $bits),*], $first_bits);
+    };
+
+    (@impl_consts_typed $storage:ty, [$first:ident: $first_ty:ident @ $first_bits:expr $(, $field:ident: $ty:ident @ $bits:expr)*], $offset:expr) => {
+        packed_bits!(@impl_const $storage, $first, $first_bits, $offset);
+        packed_bits!(@impl_consts_typed $storage, [$($field: $ty @ $bits),*], $offset + $first_bits);
+    };
+
+    (@impl_consts_typed $storage:ty, [], $offset:expr) => {};
+    (@impl_consts_typed $storage:ty, []) => {};
+
+    (@impl_const $storage:ty, $field:ident, $bits:expr, $offset:expr) => {
+        $crate::__paste! {
+            #[allow(non_snake_case)]
+            pub const [<$field:upper _BITS>]: u32 = $bits;
+            #[allow(non_snake_case)]
+            pub const [<$field:upper _OFFSET>]: u32 = $offset;
+            #[allow(non_snake_case)]
+            pub const [<$field:upper _MASK>]: $storage = packed_bits!(@mask $storage, $bits);
+        }
+    };
 }
 
 #[cfg(test)]
@@ -145,6 +451,98 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Protocol {
+        Tcp,
+        Udp,
+        Other,
+    }
+
+    impl From<u8> for Protocol {
+        fn from(value: u8) -> Self {
+            match value {
+                0 => Protocol::Tcp,
+                1 => Protocol::Udp,
+                _ => Protocol::Other,
+            }
+        }
+    }
+
+    impl From<Protocol> for u8 {
+        fn from(protocol: Protocol) -> Self {
+            match protocol {
+                Protocol::Tcp => 0,
+                Protocol::Udp => 1,
+                Protocol::Other => 2,
+            }
+        }
+    }
+
+    packed_bits! {
+        struct Segment(u8) {
+            fin: bool @ 1,
+            protocol: Protocol @ 3,
+        }
+    }
+
+    // A single field spanning the storage type's full width is legal per
+    // `PACKED_BITS_FIT` but is the one case where naively shifting by the
+    // field's bit count overflows; exercising it here catches any macro arm
+    // that regresses back to the raw `(1 << bits) - 1` formula.
+    packed_bits! {
+        struct FullByte(u8) {
+            all: 8,
+        }
+    }
+
+    packed_bits! {
+        struct FullByteTyped(u8) {
+            all: u8 @ 8,
+        }
+    }
+
+    // Asserts that `$ty`'s big- and little-endian byte (de)serialization and
+    // `from_raw` all round-trip back to `$value`'s raw representation.
+    macro_rules! assert_byte_roundtrip {
+        ($ty:ident, $value:expr) => {{
+            let value = $value;
+
+            let be = value.to_be_bytes();
+            assert_eq!(value.into_raw(), $ty::from_be_bytes(be).into_raw());
+            assert_eq!(value.into_raw(), $ty::from_bytes(&be).into_raw());
+
+            let le = value.to_le_bytes();
+            assert_eq!(value.into_raw(), $ty::from_le_bytes(le).into_raw());
+            assert_eq!(value.into_raw(), $ty::from_raw(value.into_raw()).into_raw());
+        }};
+    }
+
+    #[test]
+    fn full_width_field_does_not_overflow() {
+        let mut plain = FullByte::new(0);
+        assert_eq!(0, plain.all());
+        plain.set_all(255);
+        assert_eq!(255, plain.all());
+        assert_eq!(255, plain.with_all(255).all());
+        assert!(FullByte::try_new(255).is_ok());
+        assert_eq!(8, FullByte::ALL_BITS);
+        assert_eq!(0, FullByte::ALL_OFFSET);
+        assert_eq!(255, FullByte::ALL_MASK);
+        assert!(FullByte::try_from_bytes(&[255]).is_some());
+        assert_byte_roundtrip!(FullByte, plain);
+
+        let mut typed = FullByteTyped::new(0);
+        assert_eq!(0, typed.all());
+        typed.set_all(255);
+        assert_eq!(255, typed.all());
+        assert_eq!(255, typed.with_all(255).all());
+        assert!(FullByteTyped::try_new(255).is_ok());
+        assert_eq!(8, FullByteTyped::ALL_BITS);
+        assert_eq!(0, FullByteTyped::ALL_OFFSET);
+        assert_eq!(255, FullByteTyped::ALL_MASK);
+        assert_byte_roundtrip!(FullByteTyped, typed);
+    }
+
     #[test]
     fn basic_functionality() {
         let date = Date::new(25, 12, 99);
@@ -248,4 +646,219 @@ mod tests {
         // 100 * 1 byte
         assert_eq!(100, size_of::<[TcpFlags; 100]>());
     }
+
+    #[test]
+    fn setters_and_with_builders() {
+        let mut date = Date::new(1, 1, 1);
+        date.set_day(25);
+        assert_eq!((25, 1, 1), (date.day(), date.month(), date.year()));
+
+        // `with_` builders return a new value and leave the original copy intact.
+        let date2 = date.with_month(12).with_year(99);
+        assert_eq!((25, 1, 1), (date.day(), date.month(), date.year()));
+        assert_eq!((25, 12, 99), (date2.day(), date2.month(), date2.year()));
+
+        let mut flags = TcpFlags::new(0, 0, 0, 0, 0, 0, 0, 0);
+        flags.set_fin(1);
+        flags.set_ack(1);
+        assert_eq!((1, 0, 1), (flags.fin(), flags.syn(), flags.ack()));
+    }
+
+    // Constructing and updating a packed struct in a const context only
+    // compiles if `new` and the setters (via `with_`) are `const fn`.
+    const CONST_DATE: Date = Date::new(25, 12, 99).with_day(1);
+
+    #[test]
+    fn const_context_usage() {
+        assert_eq!((1, 12, 99), (CONST_DATE.day(), CONST_DATE.month(), CONST_DATE.year()));
+    }
+
+    #[test]
+    fn try_new_rejects_out_of_range_values() {
+        assert!(Date::try_new(31, 15, 127).is_ok());
+
+        match Date::try_new(32, 1, 1) {
+            Err(msg) => assert_eq!(msg, "packed_bits!: field `day` does not fit in its allotted bits"),
+            Ok(_) => panic!("expected an overflow error"),
+        }
+        match Date::try_new(1, 16, 1) {
+            Err(msg) => assert_eq!(msg, "packed_bits!: field `month` does not fit in its allotted bits"),
+            Ok(_) => panic!("expected an overflow error"),
+        }
+
+        let Ok(date) = Date::try_new(25, 12, 99) else {
+            panic!("expected valid field values to be accepted")
+        };
+        assert_eq!((25, 12, 99), (date.day(), date.month(), date.year()));
+    }
+
+    #[test]
+    fn typed_fields() {
+        let mut segment = Segment::new(true, Protocol::Udp);
+        assert!(segment.fin());
+        assert_eq!(Protocol::Udp, segment.protocol());
+
+        segment.set_fin(false);
+        assert!(!segment.fin());
+
+        let segment2 = segment.with_protocol(Protocol::Other);
+        assert_eq!(Protocol::Other, segment2.protocol());
+        assert_eq!(Protocol::Udp, segment.protocol());
+
+        assert!(Segment::try_new(true, Protocol::Tcp).is_ok());
+    }
+
+    #[test]
+    fn byte_serialization_roundtrip() {
+        let date = Date::new(25, 12, 99);
+
+        let be = date.to_be_bytes();
+        assert_eq!(date.into_raw(), Date::from_be_bytes(be).into_raw());
+        assert_eq!(date.into_raw(), Date::from_bytes(&be).into_raw());
+
+        let mut le = date.to_le_bytes();
+        assert_eq!(date.into_raw(), Date::from_le_bytes(le).into_raw());
+        le.reverse();
+        assert_eq!(be, le);
+
+        assert!(Date::try_from_bytes(&[0]).is_none());
+        assert!(Date::try_from_bytes(&[0, 0, 0]).is_none());
+
+        let restored = Date::from_raw(date.into_raw());
+        assert_eq!((25, 12, 99), (restored.day(), restored.month(), restored.year()));
+    }
+
+    #[test]
+    fn field_layout_constants() {
+        assert_eq!(5, Date::DAY_BITS);
+        assert_eq!(0, Date::DAY_OFFSET);
+        assert_eq!(0b11111, Date::DAY_MASK);
+
+        assert_eq!(4, Date::MONTH_BITS);
+        assert_eq!(5, Date::MONTH_OFFSET);
+        assert_eq!(0b1111, Date::MONTH_MASK);
+
+        assert_eq!(7, Date::YEAR_BITS);
+        assert_eq!(9, Date::YEAR_OFFSET);
+        assert_eq!(0b1111111, Date::YEAR_MASK);
+
+        assert_eq!(1, Segment::FIN_BITS);
+        assert_eq!(0, Segment::FIN_OFFSET);
+        assert_eq!(0b1, Segment::FIN_MASK);
+        assert_eq!(3, Segment::PROTOCOL_BITS);
+        assert_eq!(1, Segment::PROTOCOL_OFFSET);
+        assert_eq!(0b111, Segment::PROTOCOL_MASK);
+
+        assert_eq!(5, Rgb565::BLUE_BITS);
+        assert_eq!(0, Rgb565::BLUE_OFFSET);
+        assert_eq!(0b11111, Rgb565::BLUE_MASK);
+        assert_eq!(6, Rgb565::GREEN_BITS);
+        assert_eq!(5, Rgb565::GREEN_OFFSET);
+        assert_eq!(0b111111, Rgb565::GREEN_MASK);
+        assert_eq!(5, Rgb565::RED_BITS);
+        assert_eq!(11, Rgb565::RED_OFFSET);
+        assert_eq!(0b11111, Rgb565::RED_MASK);
+
+        assert_eq!(6, Time::SECOND_BITS);
+        assert_eq!(0, Time::SECOND_OFFSET);
+        assert_eq!(0b111111, Time::SECOND_MASK);
+        assert_eq!(6, Time::MINUTE_BITS);
+        assert_eq!(6, Time::MINUTE_OFFSET);
+        assert_eq!(0b111111, Time::MINUTE_MASK);
+        assert_eq!(5, Time::HOUR_BITS);
+        assert_eq!(12, Time::HOUR_OFFSET);
+        assert_eq!(0b11111, Time::HOUR_MASK);
+
+        assert_eq!(1, TcpFlags::FIN_BITS);
+        assert_eq!(0, TcpFlags::FIN_OFFSET);
+        assert_eq!(0b1, TcpFlags::FIN_MASK);
+        assert_eq!(1, TcpFlags::SYN_BITS);
+        assert_eq!(1, TcpFlags::SYN_OFFSET);
+        assert_eq!(0b1, TcpFlags::SYN_MASK);
+        assert_eq!(1, TcpFlags::_RST_BITS);
+        assert_eq!(2, TcpFlags::_RST_OFFSET);
+        assert_eq!(0b1, TcpFlags::_RST_MASK);
+        assert_eq!(1, TcpFlags::_PSH_BITS);
+        assert_eq!(3, TcpFlags::_PSH_OFFSET);
+        assert_eq!(0b1, TcpFlags::_PSH_MASK);
+        assert_eq!(1, TcpFlags::ACK_BITS);
+        assert_eq!(4, TcpFlags::ACK_OFFSET);
+        assert_eq!(0b1, TcpFlags::ACK_MASK);
+        assert_eq!(1, TcpFlags::_URG_BITS);
+        assert_eq!(5, TcpFlags::_URG_OFFSET);
+        assert_eq!(0b1, TcpFlags::_URG_MASK);
+        assert_eq!(1, TcpFlags::_ECE_BITS);
+        assert_eq!(6, TcpFlags::_ECE_OFFSET);
+        assert_eq!(0b1, TcpFlags::_ECE_MASK);
+        assert_eq!(1, TcpFlags::_CWR_BITS);
+        assert_eq!(7, TcpFlags::_CWR_OFFSET);
+        assert_eq!(0b1, TcpFlags::_CWR_MASK);
+    }
+
+    #[test]
+    fn rgb565_setters_and_try_new() {
+        assert!(Rgb565::try_new(31, 63, 31).is_ok());
+        assert!(Rgb565::try_new(32, 0, 0).is_err());
+
+        let mut color = Rgb565::new(0, 0, 0);
+        color.set_red(31);
+        let color2 = color.with_blue(31).with_green(63).with_red(0);
+        assert_eq!((0, 0, 31), (color.blue(), color.green(), color.red()));
+        assert_eq!((31, 63, 0), (color2.blue(), color2.green(), color2.red()));
+
+        assert_byte_roundtrip!(Rgb565, color2);
+    }
+
+    #[test]
+    fn time_setters_and_try_new() {
+        assert!(Time::try_new(59, 59, 23).is_ok());
+        assert!(Time::try_new(64, 0, 0).is_err());
+
+        let mut time = Time::new(0, 0, 0);
+        time.set_second(30);
+        let time2 = time.with_second(0).with_minute(15).with_hour(12);
+        assert_eq!((30, 0, 0), (time.second(), time.minute(), time.hour()));
+        assert_eq!((0, 15, 12), (time2.second(), time2.minute(), time2.hour()));
+
+        assert!(Time::try_from_bytes(&[0, 0, 0]).is_none());
+        assert_byte_roundtrip!(Time, time2);
+    }
+
+    #[test]
+    fn tcp_flags_every_field_setter_and_const() {
+        assert!(TcpFlags::try_new(1, 1, 1, 1, 1, 1, 1, 1).is_ok());
+        assert!(TcpFlags::try_new(2, 0, 0, 0, 0, 0, 0, 0).is_err());
+
+        let mut flags = TcpFlags::new(0, 0, 0, 0, 0, 0, 0, 0);
+        flags.set_fin(1);
+        flags.set_syn(1);
+        flags.set__rst(1);
+        flags.set__psh(1);
+        flags.set_ack(1);
+        flags.set__urg(1);
+        flags.set__ece(1);
+        flags.set__cwr(1);
+        assert_eq!(0b1111_1111, flags.into_raw());
+
+        let flags2 = TcpFlags::new(0, 0, 0, 0, 0, 0, 0, 0)
+            .with_fin(1)
+            .with_syn(1)
+            .with__rst(1)
+            .with__psh(1)
+            .with_ack(1)
+            .with__urg(1)
+            .with__ece(1)
+            .with__cwr(1);
+        assert_eq!(flags.into_raw(), flags2.into_raw());
+
+        assert_byte_roundtrip!(TcpFlags, flags);
+    }
+
+    #[test]
+    fn segment_with_fin_and_bytes() {
+        let segment = Segment::new(true, Protocol::Udp).with_fin(false);
+        assert!(!segment.fin());
+
+        assert_byte_roundtrip!(Segment, segment);
+    }
 }